@@ -4,6 +4,8 @@
 /// sigma - volatility (%)
 /// r - continuously compounded risk-free interest rate (%)
 /// q - continously compounded dividend yield (%)
+/// b - cost-of-carry rate (%): `r - q` for stocks, `r` for non-dividend
+///     stocks, `0` for futures (Black-76), `r - r_f` for FX (Garman-Kohlhagen)
 /// t - time to expiration (% of year)
 /// days_per_year - number of days per year (generally 365)
 use std::f64::consts::E;
@@ -14,34 +16,27 @@ use stats::cnd;
 ///
 /// Delta measures the rate of the theoretical option value with respect to the changes in the underlying asset's price.
 pub fn delta_call(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let d1 = d1(s0, x, t, r, q, sigma);
-    let cnd = cnd(d1);
-    let e = E.powf(-(q * t));
-    return e * cnd;
+    return delta_call_gbs(s0, x, t, r, r - q, sigma);
 }
 
 /// Calculates the delta of a put options
 ///
 /// Delta measures the rate of the theoretical option value with respect to the changes in the underlying asset's price.
 pub fn delta_put(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let d1 = d1(s0, x, t, r, q, sigma);
-    let cnd = cnd(d1);
-    let e = E.powf(-(q * t));
-    return e * (cnd - 1.0);
+    return delta_put_gbs(s0, x, t, r, r - q, sigma);
 }
 
 /// Calculates the Gamma for an option
 ///
 /// Gamma measures the rate of change in the delta with respect to the change in the underlying price.
 pub fn gamma(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let d1 = d1(s0, x, t, r, q, sigma);
-    return gamma_d1(s0, t, q, sigma, d1);
+    return gamma_gbs(s0, x, t, r, r - q, sigma);
 }
 
 pub fn gamma_d1(s0: f64, t: f64, q: f64, sigma: f64, d1: f64) -> f64 {
     let arg1 = E.powf(-(q * t)) / (s0 * sigma * (t.sqrt()));
     let arg2 = one_over_sqrt_pi();
-    let arg3 = E.powf((-d1).powf(2.0)) / 2.0;
+    let arg3 = E.powf(-(d1.powf(2.0)) / 2.0);
     return arg1 * arg2 * arg3;
 }
 
@@ -86,8 +81,7 @@ fn theta_arg_3(s0: f64, t: f64, q: f64, d1: f64) -> f64 {
 ///
 /// Vega measures the sensitivity to volatility. Vega is the derivative of the option value with respect to the volatility of the underlying asset.
 pub fn vega(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let d1 = d1(s0, x, t, r, q, sigma);
-    return vega_d1(s0, t, q, d1);
+    return vega_gbs(s0, x, t, r, r - q, sigma);
 }
 
 pub fn vega_d1(s0: f64, t: f64, q: f64, d1: f64) -> f64 {
@@ -101,16 +95,113 @@ pub fn vega_d1(s0: f64, t: f64, q: f64, d1: f64) -> f64 {
 ///
 /// Rho measures the sensitivity to the interest rate. Rho is the derivative of the option value with respect to the risk free interest rate.
 pub fn rho_call(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let d2_cnd = cnd(d2(s0, x, t, r, q, sigma));
-    return (1.0 / 100.0) * x * t * E.powf(-r * t) * d2_cnd;
+    return rho_call_gbs(s0, x, t, r, r - q, sigma);
 }
 
 /// Calculates the Rho of a put option
 ///
 /// Rho measures the sensitivity to the interest rate. Rho is the derivative of the option value with respect to the risk free interest rate.
 pub fn rho_put(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let neg_d2_cnd = cnd(-d2(s0, x, t, r, q, sigma));
-    return -(1.0 / 100.0) * x * t * E.powf(-r * t) * neg_d2_cnd;
+    return rho_put_gbs(s0, x, t, r, r - q, sigma);
+}
+
+/// Calculates the theoretical price of a call option.
+pub fn call_price(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    return call_price_gbs(s0, x, t, r, r - q, sigma);
+}
+
+/// Calculates the theoretical price of a put option.
+pub fn put_price(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    return put_price_gbs(s0, x, t, r, r - q, sigma);
+}
+
+/// Vega without the per-1%-move scaling `vega_d1` applies, i.e. the raw
+/// analytic derivative of the option price with respect to volatility.
+/// The implied volatility solver divides by this, not the per-1%-move number.
+fn vega_raw(s0: f64, t: f64, q: f64, d1: f64) -> f64 {
+    return s0 * E.powf(-(q * t)) * t.sqrt() * one_over_sqrt_pi() * E.powf(-d1.powf(2.0) / 2.0);
+}
+
+const IMPLIED_VOL_TOLERANCE: f64 = 1e-8;
+const IMPLIED_VOL_MAX_ITERATIONS: u32 = 100;
+const IMPLIED_VOL_BISECTION_ITERATIONS: u32 = 200;
+const IMPLIED_VOL_LOWER_BOUND: f64 = 1e-6;
+const IMPLIED_VOL_UPPER_BOUND: f64 = 5.0;
+
+/// Recovers the implied volatility of a call option from its market price
+/// using Newton-Raphson, started from the Brenner-Subrahmanyam guess
+/// `sqrt(2*pi/t)*(price/s0)`, falling back to bisection on `[1e-6, 5.0]`
+/// when a Newton step leaves the bracket or vega underflows. Returns `None`
+/// when `price` is below the option's intrinsic value, i.e. there is no solution.
+pub fn implied_vol_call(price: f64, s0: f64, x: f64, t: f64, r: f64, q: f64) -> Option<f64> {
+    let intrinsic = (s0 * E.powf(-(q * t)) - x * E.powf(-(r * t))).max(0.0);
+    if price < intrinsic {
+        return None;
+    }
+    return newton_with_bisection(price, s0, x, t, r, q, call_price);
+}
+
+/// Recovers the implied volatility of a put option from its market price.
+/// See `implied_vol_call` for the solver details.
+pub fn implied_vol_put(price: f64, s0: f64, x: f64, t: f64, r: f64, q: f64) -> Option<f64> {
+    let intrinsic = (x * E.powf(-(r * t)) - s0 * E.powf(-(q * t))).max(0.0);
+    if price < intrinsic {
+        return None;
+    }
+    return newton_with_bisection(price, s0, x, t, r, q, put_price);
+}
+
+fn newton_with_bisection(price: f64,
+                          s0: f64,
+                          x: f64,
+                          t: f64,
+                          r: f64,
+                          q: f64,
+                          pricer: fn(f64, f64, f64, f64, f64, f64) -> f64)
+                          -> Option<f64> {
+    let mut sigma = (2.0 * PI / t).sqrt() * (price / s0);
+    for _ in 0..IMPLIED_VOL_MAX_ITERATIONS {
+        let diff = pricer(s0, x, t, r, q, sigma) - price;
+        if diff.abs() < IMPLIED_VOL_TOLERANCE {
+            return Some(sigma);
+        }
+        let d1 = d1(s0, x, t, r, q, sigma);
+        let v = vega_raw(s0, t, q, d1);
+        if v.abs() < IMPLIED_VOL_TOLERANCE {
+            break;
+        }
+        let next = sigma - diff / v;
+        if next <= IMPLIED_VOL_LOWER_BOUND || next >= IMPLIED_VOL_UPPER_BOUND {
+            break;
+        }
+        sigma = next;
+    }
+    return bisect(price, s0, x, t, r, q, pricer);
+}
+
+fn bisect(price: f64,
+          s0: f64,
+          x: f64,
+          t: f64,
+          r: f64,
+          q: f64,
+          pricer: fn(f64, f64, f64, f64, f64, f64) -> f64)
+          -> Option<f64> {
+    let mut lo = IMPLIED_VOL_LOWER_BOUND;
+    let mut hi = IMPLIED_VOL_UPPER_BOUND;
+    for _ in 0..IMPLIED_VOL_BISECTION_ITERATIONS {
+        let mid = (lo + hi) / 2.0;
+        let diff = pricer(s0, x, t, r, q, mid) - price;
+        if diff.abs() < IMPLIED_VOL_TOLERANCE {
+            return Some(mid);
+        }
+        if diff < 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    return Some((lo + hi) / 2.0);
 }
 
 fn one_over_sqrt_pi() -> f64 {
@@ -118,9 +209,7 @@ fn one_over_sqrt_pi() -> f64 {
 }
 
 pub fn d1(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
-    let ln = (s0 / x).ln();
-    let t_num = t * (r - q + (sigma.powf(2f64) / 2f64));
-    return (ln + t_num) / (sigma * t.sqrt());
+    return d1_gbs(s0, x, t, r - q, sigma);
 }
 
 // TODO Add overload for providing d1
@@ -133,6 +222,356 @@ pub fn d2_d1(t: f64, sigma: f64, d1: f64) -> f64 {
     return d1 - (t.sqrt() * sigma);
 }
 
+/// Generalized Black-Scholes d1 under the cost-of-carry model. Stocks,
+/// futures (Black-76, `b = 0`) and FX (Garman-Kohlhagen, `b = r - r_f`) all
+/// reduce to this single formula by choosing `b` appropriately.
+pub fn d1_gbs(s0: f64, x: f64, t: f64, b: f64, sigma: f64) -> f64 {
+    let ln = (s0 / x).ln();
+    let t_num = t * (b + (sigma.powf(2f64) / 2f64));
+    return (ln + t_num) / (sigma * t.sqrt());
+}
+
+pub fn d2_gbs(s0: f64, x: f64, t: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    return d1 - (t.sqrt() * sigma);
+}
+
+/// Calculates the delta of a call option under the generalized
+/// cost-of-carry model. See the module docs for how `b` maps to each
+/// option family.
+pub fn delta_call_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    return E.powf((b - r) * t) * cnd(d1);
+}
+
+/// Calculates the delta of a put option under the generalized
+/// cost-of-carry model.
+pub fn delta_put_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    return E.powf((b - r) * t) * (cnd(d1) - 1.0);
+}
+
+/// Calculates the Gamma of an option under the generalized cost-of-carry
+/// model. Gamma is the same for calls and puts.
+pub fn gamma_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    let arg1 = E.powf((b - r) * t) / (s0 * sigma * t.sqrt());
+    let arg2 = one_over_sqrt_pi();
+    let arg3 = E.powf(-(d1.powf(2.0)) / 2.0);
+    return arg1 * arg2 * arg3;
+}
+
+/// Calculates the Vega of an option under the generalized cost-of-carry
+/// model. Vega is the same for calls and puts.
+pub fn vega_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    let mult1 = (1.0 / 100.0) * s0 * E.powf((b - r) * t) * t.sqrt();
+    let mult2 = one_over_sqrt_pi();
+    let mult3 = E.powf(-(d1.powf(2.0)) / 2.0);
+    return mult1 * mult2 * mult3;
+}
+
+/// Calculates the Rho of a call option under the generalized cost-of-carry
+/// model. Treats `b` as moving one-for-one with `r` (as `b = r - q` and
+/// `b = r - r_f` do); with `b` held fixed, as for futures (`b = 0`), the
+/// derivative with respect to `r` is `-t` times the price, which this does
+/// not return.
+pub fn rho_call_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d2 = d2_gbs(s0, x, t, b, sigma);
+    return (1.0 / 100.0) * x * t * E.powf(-r * t) * cnd(d2);
+}
+
+/// Calculates the Rho of a put option under the generalized cost-of-carry
+/// model. As with `rho_call_gbs`, `b` is treated as moving one-for-one with
+/// `r`; the fixed-`b` (futures) case is not covered.
+pub fn rho_put_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d2 = d2_gbs(s0, x, t, b, sigma);
+    return -(1.0 / 100.0) * x * t * E.powf(-r * t) * cnd(-d2);
+}
+
+/// Calculates the theoretical price of a call option under the generalized
+/// cost-of-carry model.
+pub fn call_price_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    let arg1 = s0 * E.powf((b - r) * t) * cnd(d1);
+    let arg2 = x * E.powf(-(r * t)) * cnd(d2);
+    return arg1 - arg2;
+}
+
+/// Calculates the theoretical price of a put option under the generalized
+/// cost-of-carry model.
+pub fn put_price_gbs(s0: f64, x: f64, t: f64, r: f64, b: f64, sigma: f64) -> f64 {
+    let d1 = d1_gbs(s0, x, t, b, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    let arg1 = x * E.powf(-(r * t)) * cnd(-d2);
+    let arg2 = s0 * E.powf((b - r) * t) * cnd(-d1);
+    return arg1 - arg2;
+}
+
+/// The standard normal PDF, `phi(d1)`, as used by vega and the other
+/// Greeks below it.
+fn phi(d1: f64) -> f64 {
+    return one_over_sqrt_pi() * E.powf(-d1.powf(2.0) / 2.0);
+}
+
+/// Calculates Vanna, the sensitivity of delta to volatility (equivalently,
+/// of vega to the underlying price).
+pub fn vanna(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    let d1 = d1(s0, x, t, r, q, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    return -E.powf(-(q * t)) * phi(d1) * d2 / sigma;
+}
+
+/// Calculates Charm for a call option, the rate of change of delta with
+/// respect to the time to expiry `t` (per year). Delta changes by the
+/// negative of this as time passes.
+pub fn charm_call(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    let d1 = d1(s0, x, t, r, q, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    let carry = E.powf(-(q * t)) * phi(d1) * (2.0 * (r - q) * t - d2 * sigma * t.sqrt()) /
+                (2.0 * t * sigma * t.sqrt());
+    return carry - q * E.powf(-(q * t)) * cnd(d1);
+}
+
+/// Calculates Charm for a put option, the rate of change of delta with
+/// respect to the time to expiry `t` (per year). Delta changes by the
+/// negative of this as time passes.
+pub fn charm_put(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    let d1 = d1(s0, x, t, r, q, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    let carry = E.powf(-(q * t)) * phi(d1) * (2.0 * (r - q) * t - d2 * sigma * t.sqrt()) /
+                (2.0 * t * sigma * t.sqrt());
+    return carry + q * E.powf(-(q * t)) * cnd(-d1);
+}
+
+/// Calculates Vomma, the sensitivity of vega to volatility.
+pub fn vomma(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    let d1 = d1(s0, x, t, r, q, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    return vega_raw(s0, t, q, d1) * d1 * d2 / sigma;
+}
+
+/// Calculates Speed, the sensitivity of gamma to the underlying price.
+pub fn speed(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    let d1 = d1(s0, x, t, r, q, sigma);
+    let arg1 = -E.powf(-(q * t)) * phi(d1) / (s0.powf(2.0) * sigma * t.sqrt());
+    let arg2 = d1 / (sigma * t.sqrt()) + 1.0;
+    return arg1 * arg2;
+}
+
+/// Calculates Zomma, the sensitivity of gamma to volatility.
+pub fn zomma(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64) -> f64 {
+    let d1 = d1(s0, x, t, r, q, sigma);
+    let d2 = d2_d1(t, sigma, d1);
+    return E.powf(-(q * t)) * phi(d1) * (d1 * d2 - 1.0) / (s0 * sigma.powf(2.0) * t.sqrt());
+}
+
+/// Prices an American call option with a Cox-Ross-Rubinstein binomial tree,
+/// capturing the early exercise premium that the closed-form Black-Scholes
+/// formula cannot express.
+pub fn american_call(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64, steps: u32) -> f64 {
+    return american_tree(s0, x, t, r, q, sigma, steps, |spot, strike| (spot - strike).max(0.0));
+}
+
+/// Prices an American put option with a Cox-Ross-Rubinstein binomial tree.
+pub fn american_put(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64, steps: u32) -> f64 {
+    return american_tree(s0, x, t, r, q, sigma, steps, |spot, strike| (strike - spot).max(0.0));
+}
+
+fn american_tree<F>(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64, steps: u32, payoff: F) -> f64
+    where F: Fn(f64, f64) -> f64
+{
+    let steps = steps as usize;
+    let dt = t / (steps as f64);
+    let u = E.powf(sigma * dt.sqrt());
+    let d = 1.0 / u;
+    let p = (E.powf((r - q) * dt) - d) / (u - d);
+    let disc = E.powf(-r * dt);
+
+    let mut values: Vec<f64> = (0..=steps)
+        .map(|i| {
+            let spot = s0 * u.powi((steps - i) as i32) * d.powi(i as i32);
+            payoff(spot, x)
+        })
+        .collect();
+
+    for step in (0..steps).rev() {
+        for i in 0..=step {
+            let spot = s0 * u.powi((step - i) as i32) * d.powi(i as i32);
+            let continuation = disc * (p * values[i] + (1.0 - p) * values[i + 1]);
+            values[i] = continuation.max(payoff(spot, x));
+        }
+    }
+    return values[0];
+}
+
+/// A minimal xorshift64* PRNG, used instead of an external crate so Monte
+/// Carlo runs stay reproducible from a caller-supplied seed.
+struct Xorshift64Star {
+    state: u64,
+}
+
+impl Xorshift64Star {
+    fn new(seed: u64) -> Xorshift64Star {
+        let state = if seed == 0 { 0x9E3779B97F4A7C15 } else { seed };
+        return Xorshift64Star { state: state };
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        return x.wrapping_mul(0x2545F4914F6CDD1D);
+    }
+
+    /// A uniform sample in `(0, 1)`.
+    fn next_uniform(&mut self) -> f64 {
+        let x = self.next_u64();
+        let u = ((x >> 11) as f64) * (1.0 / ((1u64 << 53) as f64));
+        return u.max(1e-12);
+    }
+
+    /// A standard normal sample via Box-Muller.
+    fn next_standard_normal(&mut self) -> f64 {
+        let u1 = self.next_uniform();
+        let u2 = self.next_uniform();
+        return (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+    }
+}
+
+/// Prices a European call via Monte Carlo simulation of terminal prices
+/// under geometric Brownian motion, as a cross-check against `call_price`
+/// and a foundation for payoffs the closed-form formula can't express.
+/// `seed` makes the simulation reproducible; a fixed non-zero seed is
+/// substituted if `0` is passed.
+pub fn mc_call_price(s0: f64,
+                      x: f64,
+                      t: f64,
+                      r: f64,
+                      q: f64,
+                      sigma: f64,
+                      num_sims: u32,
+                      seed: u64)
+                      -> f64 {
+    let mut rng = Xorshift64Star::new(seed);
+    let drift = (r - q - sigma.powf(2.0) / 2.0) * t;
+    let diffusion = sigma * t.sqrt();
+    let mut total = 0.0;
+    for _ in 0..num_sims {
+        let z = rng.next_standard_normal();
+        let s_t = s0 * E.powf(drift + diffusion * z);
+        total += (s_t - x).max(0.0);
+    }
+    return E.powf(-r * t) * (total / (num_sims as f64));
+}
+
+/// The standard sensitivities of an option, computed from a single
+/// `d1`/`d2` so callers don't pay to recompute them across delta, gamma,
+/// theta, vega and rho individually.
+#[derive(Debug, Clone, Copy)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub rho: f64,
+}
+
+/// An option's theoretical price bundled with its Greeks, both derived
+/// from a single `d1`/`d2` computation.
+#[derive(Debug, Clone, Copy)]
+pub struct OptionValue {
+    pub price: f64,
+    pub greeks: Greeks,
+}
+
+/// A Black-Scholes option, bundling the six pricing parameters so a caller
+/// can compute price and Greeks together without re-passing them (and
+/// without each Greek recomputing `d1` from scratch, as the free functions
+/// do).
+pub struct BlackScholesOption {
+    pub s0: f64,
+    pub x: f64,
+    pub t: f64,
+    pub r: f64,
+    pub q: f64,
+    pub sigma: f64,
+    pub days_per_year: f64,
+}
+
+impl BlackScholesOption {
+    pub fn new(s0: f64, x: f64, t: f64, r: f64, q: f64, sigma: f64, days_per_year: f64) -> BlackScholesOption {
+        return BlackScholesOption {
+            s0: s0,
+            x: x,
+            t: t,
+            r: r,
+            q: q,
+            sigma: sigma,
+            days_per_year: days_per_year,
+        };
+    }
+
+    /// Computes price and Greeks for the call, sharing one `d1`/`d2`.
+    pub fn call_greeks(&self) -> OptionValue {
+        let d1 = d1(self.s0, self.x, self.t, self.r, self.q, self.sigma);
+        let d2 = d2_d1(self.t, self.sigma, d1);
+        let e_qt = E.powf(-(self.q * self.t));
+
+        let price = self.s0 * e_qt * cnd(d1) - self.x * E.powf(-(self.r * self.t)) * cnd(d2);
+        let delta = e_qt * cnd(d1);
+        let gamma = gamma_d1(self.s0, self.t, self.q, self.sigma, d1);
+        let vega = vega_d1(self.s0, self.t, self.q, d1);
+        let arg1 = theta_arg_1(self.s0, self.t, self.q, self.sigma, d1);
+        let arg2 = theta_arg_2(self.x, self.t, self.r, d2);
+        let arg3 = theta_arg_3(self.s0, self.t, self.q, d1);
+        let theta = (1.0 / self.days_per_year) * (arg1 - arg2 + arg3);
+        let rho = (1.0 / 100.0) * self.x * self.t * E.powf(-self.r * self.t) * cnd(d2);
+
+        return OptionValue {
+            price: price,
+            greeks: Greeks {
+                delta: delta,
+                gamma: gamma,
+                theta: theta,
+                vega: vega,
+                rho: rho,
+            },
+        };
+    }
+
+    /// Computes price and Greeks for the put, sharing one `d1`/`d2`.
+    pub fn put_greeks(&self) -> OptionValue {
+        let d1 = d1(self.s0, self.x, self.t, self.r, self.q, self.sigma);
+        let d2 = d2_d1(self.t, self.sigma, d1);
+        let e_qt = E.powf(-(self.q * self.t));
+
+        let price = self.x * E.powf(-(self.r * self.t)) * cnd(-d2) - self.s0 * e_qt * cnd(-d1);
+        let delta = e_qt * (cnd(d1) - 1.0);
+        let gamma = gamma_d1(self.s0, self.t, self.q, self.sigma, d1);
+        let vega = vega_d1(self.s0, self.t, self.q, d1);
+        let arg1 = theta_arg_1(self.s0, self.t, self.q, self.sigma, d1);
+        let arg2 = theta_arg_2(self.x, self.t, self.r, -d2);
+        let arg3 = theta_arg_3(self.s0, self.t, self.q, -d1);
+        let theta = (1.0 / self.days_per_year) * (arg1 + arg2 - arg3);
+        let rho = -(1.0 / 100.0) * self.x * self.t * E.powf(-self.r * self.t) * cnd(-d2);
+
+        return OptionValue {
+            price: price,
+            greeks: Greeks {
+                delta: delta,
+                gamma: gamma,
+                theta: theta,
+                vega: vega,
+                rho: rho,
+            },
+        };
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -150,12 +589,33 @@ mod tests {
     const E_D2: f64 = -0.1053;
     const E_CALL_DELTA: f64 = 0.5079;
     const E_PUT_DELTA: f64 = -0.4908;
-    const E_GAMMA: f64 = 0.0243;
+    const E_GAMMA: f64 = 0.0486;
     const E_THETA_CALL: f64 = -0.0703;
     const E_THETA_PUT: f64 = -0.0714;
     const E_VEGA: f64 = 0.0647;
     const E_RHO_CALL: f64 = 0.0187;
     const E_RHO_PUT: f64 = -0.0222;
+    const E_CALL_PRICE: f64 = 3.1047;
+    const E_PUT_PRICE: f64 = 3.4488;
+    const E_IMPLIED_VOL: f64 = 0.5051;
+
+    const FUTURES_PRICE: f64 = 100.0;
+    const FUTURES_STRIKE: f64 = 100.0;
+    const FUTURES_TIME_TO_EXPIRY: f64 = 0.5;
+    const FUTURES_INTEREST_RATE: f64 = 0.05;
+    const FUTURES_VOL: f64 = 0.2;
+    const E_FUTURES_CALL_PRICE: f64 = 5.4980;
+
+    const BINOMIAL_STEPS: u32 = 100;
+    const E_AMERICAN_CALL: f64 = 3.1083;
+    const E_AMERICAN_PUT: f64 = 3.4509;
+
+    const E_VANNA: f64 = 0.0830;
+    const E_CHARM_CALL: f64 = 0.3033;
+    const E_CHARM_PUT: f64 = 0.3243;
+    const E_VOMMA: f64 = -0.0290;
+    const E_SPEED: f64 = -0.0009;
+    const E_ZOMMA: f64 = -0.0964;
 
     #[test]
     fn test_d1() {
@@ -281,4 +741,335 @@ mod tests {
         assert!(abs < 0.001);
     }
 
+    #[test]
+    fn test_call_price() {
+        let call_price = call_price(UNDERLYING,
+                                    STRIKE,
+                                    TIME_TO_EXPIRY,
+                                    INTEREST_RATE,
+                                    DIV_YIELD,
+                                    VOL);
+        let abs = (call_price - E_CALL_PRICE).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_put_price() {
+        let put_price = put_price(UNDERLYING,
+                                  STRIKE,
+                                  TIME_TO_EXPIRY,
+                                  INTEREST_RATE,
+                                  DIV_YIELD,
+                                  VOL);
+        let abs = (put_price - E_PUT_PRICE).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_implied_vol_call() {
+        let iv = implied_vol_call(E_CALL_PRICE,
+                                  UNDERLYING,
+                                  STRIKE,
+                                  TIME_TO_EXPIRY,
+                                  INTEREST_RATE,
+                                  DIV_YIELD).unwrap();
+        let abs = (iv - E_IMPLIED_VOL).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_implied_vol_put() {
+        let iv = implied_vol_put(E_PUT_PRICE,
+                                 UNDERLYING,
+                                 STRIKE,
+                                 TIME_TO_EXPIRY,
+                                 INTEREST_RATE,
+                                 DIV_YIELD).unwrap();
+        let abs = (iv - E_IMPLIED_VOL).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_implied_vol_call_below_intrinsic_returns_none() {
+        let iv = implied_vol_call(-1.0,
+                                  UNDERLYING,
+                                  STRIKE,
+                                  TIME_TO_EXPIRY,
+                                  INTEREST_RATE,
+                                  DIV_YIELD);
+        assert!(iv.is_none());
+    }
+
+    #[test]
+    fn test_call_price_gbs_matches_dividend_model() {
+        let b = INTEREST_RATE - DIV_YIELD;
+        let price = call_price_gbs(UNDERLYING,
+                                   STRIKE,
+                                   TIME_TO_EXPIRY,
+                                   INTEREST_RATE,
+                                   b,
+                                   VOL);
+        let abs = (price - E_CALL_PRICE).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_call_price_gbs_futures_black76() {
+        // Black-76: cost-of-carry b = 0 for a futures underlying.
+        let price = call_price_gbs(FUTURES_PRICE,
+                                   FUTURES_STRIKE,
+                                   FUTURES_TIME_TO_EXPIRY,
+                                   FUTURES_INTEREST_RATE,
+                                   0.0,
+                                   FUTURES_VOL);
+        let abs = (price - E_FUTURES_CALL_PRICE).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_gamma_gbs_matches_dividend_model() {
+        let b = INTEREST_RATE - DIV_YIELD;
+        let gamma = gamma_gbs(UNDERLYING,
+                              STRIKE,
+                              TIME_TO_EXPIRY,
+                              INTEREST_RATE,
+                              b,
+                              VOL);
+        let abs = (gamma - E_GAMMA).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_vega_gbs_matches_dividend_model() {
+        let b = INTEREST_RATE - DIV_YIELD;
+        let vega = vega_gbs(UNDERLYING,
+                            STRIKE,
+                            TIME_TO_EXPIRY,
+                            INTEREST_RATE,
+                            b,
+                            VOL);
+        let abs = (vega - E_VEGA).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_rho_call_gbs_matches_dividend_model() {
+        let b = INTEREST_RATE - DIV_YIELD;
+        let rho = rho_call_gbs(UNDERLYING,
+                               STRIKE,
+                               TIME_TO_EXPIRY,
+                               INTEREST_RATE,
+                               b,
+                               VOL);
+        let abs = (rho - E_RHO_CALL).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_rho_put_gbs_matches_dividend_model() {
+        let b = INTEREST_RATE - DIV_YIELD;
+        let rho = rho_put_gbs(UNDERLYING,
+                              STRIKE,
+                              TIME_TO_EXPIRY,
+                              INTEREST_RATE,
+                              b,
+                              VOL);
+        let abs = (rho - E_RHO_PUT).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_american_call() {
+        let price = american_call(UNDERLYING,
+                                  STRIKE,
+                                  TIME_TO_EXPIRY,
+                                  INTEREST_RATE,
+                                  DIV_YIELD,
+                                  VOL,
+                                  BINOMIAL_STEPS);
+        let abs = (price - E_AMERICAN_CALL).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_american_put() {
+        let price = american_put(UNDERLYING,
+                                 STRIKE,
+                                 TIME_TO_EXPIRY,
+                                 INTEREST_RATE,
+                                 DIV_YIELD,
+                                 VOL,
+                                 BINOMIAL_STEPS);
+        let abs = (price - E_AMERICAN_PUT).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_american_put_at_least_european_put() {
+        // Early exercise can only add value, never subtract it.
+        let american = american_put(UNDERLYING,
+                                    STRIKE,
+                                    TIME_TO_EXPIRY,
+                                    INTEREST_RATE,
+                                    DIV_YIELD,
+                                    VOL,
+                                    BINOMIAL_STEPS);
+        let european = put_price(UNDERLYING,
+                                 STRIKE,
+                                 TIME_TO_EXPIRY,
+                                 INTEREST_RATE,
+                                 DIV_YIELD,
+                                 VOL);
+        assert!(american >= european - 0.001);
+    }
+
+    #[test]
+    fn test_mc_call_price_converges_to_analytic_price() {
+        let analytic = call_price(UNDERLYING,
+                                  STRIKE,
+                                  TIME_TO_EXPIRY,
+                                  INTEREST_RATE,
+                                  DIV_YIELD,
+                                  VOL);
+        let mc = mc_call_price(UNDERLYING,
+                               STRIKE,
+                               TIME_TO_EXPIRY,
+                               INTEREST_RATE,
+                               DIV_YIELD,
+                               VOL,
+                               200_000,
+                               12345);
+        // A few standard errors of slack for a 200k-path simulation.
+        let abs = (mc - analytic).abs();
+        assert!(abs < 0.05);
+    }
+
+    #[test]
+    fn test_mc_call_price_is_reproducible_for_a_given_seed() {
+        let first = mc_call_price(UNDERLYING,
+                                  STRIKE,
+                                  TIME_TO_EXPIRY,
+                                  INTEREST_RATE,
+                                  DIV_YIELD,
+                                  VOL,
+                                  1_000,
+                                  42);
+        let second = mc_call_price(UNDERLYING,
+                                   STRIKE,
+                                   TIME_TO_EXPIRY,
+                                   INTEREST_RATE,
+                                   DIV_YIELD,
+                                   VOL,
+                                   1_000,
+                                   42);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_black_scholes_option_call_greeks_matches_free_functions() {
+        let option = BlackScholesOption::new(UNDERLYING,
+                                             STRIKE,
+                                             TIME_TO_EXPIRY,
+                                             INTEREST_RATE,
+                                             DIV_YIELD,
+                                             VOL,
+                                             DAYS_PER_YEAR);
+        let result = option.call_greeks();
+        assert!((result.price - E_CALL_PRICE).abs() < 0.001);
+        assert!((result.greeks.delta - E_CALL_DELTA).abs() < 0.001);
+        assert!((result.greeks.gamma - E_GAMMA).abs() < 0.001);
+        assert!((result.greeks.theta - E_THETA_CALL).abs() < 0.001);
+        assert!((result.greeks.vega - E_VEGA).abs() < 0.001);
+        assert!((result.greeks.rho - E_RHO_CALL).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_black_scholes_option_put_greeks_matches_free_functions() {
+        let option = BlackScholesOption::new(UNDERLYING,
+                                             STRIKE,
+                                             TIME_TO_EXPIRY,
+                                             INTEREST_RATE,
+                                             DIV_YIELD,
+                                             VOL,
+                                             DAYS_PER_YEAR);
+        let result = option.put_greeks();
+        assert!((result.price - E_PUT_PRICE).abs() < 0.001);
+        assert!((result.greeks.delta - E_PUT_DELTA).abs() < 0.001);
+        assert!((result.greeks.gamma - E_GAMMA).abs() < 0.001);
+        assert!((result.greeks.theta - E_THETA_PUT).abs() < 0.001);
+        assert!((result.greeks.vega - E_VEGA).abs() < 0.001);
+        assert!((result.greeks.rho - E_RHO_PUT).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_vanna() {
+        let vanna = vanna(UNDERLYING,
+                          STRIKE,
+                          TIME_TO_EXPIRY,
+                          INTEREST_RATE,
+                          DIV_YIELD,
+                          VOL);
+        let abs = (vanna - E_VANNA).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_charm_call() {
+        let charm = charm_call(UNDERLYING,
+                               STRIKE,
+                               TIME_TO_EXPIRY,
+                               INTEREST_RATE,
+                               DIV_YIELD,
+                               VOL);
+        let abs = (charm - E_CHARM_CALL).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_charm_put() {
+        let charm = charm_put(UNDERLYING,
+                              STRIKE,
+                              TIME_TO_EXPIRY,
+                              INTEREST_RATE,
+                              DIV_YIELD,
+                              VOL);
+        let abs = (charm - E_CHARM_PUT).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_vomma() {
+        let vomma = vomma(UNDERLYING,
+                          STRIKE,
+                          TIME_TO_EXPIRY,
+                          INTEREST_RATE,
+                          DIV_YIELD,
+                          VOL);
+        let abs = (vomma - E_VOMMA).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_speed() {
+        let speed = speed(UNDERLYING,
+                          STRIKE,
+                          TIME_TO_EXPIRY,
+                          INTEREST_RATE,
+                          DIV_YIELD,
+                          VOL);
+        let abs = (speed - E_SPEED).abs();
+        assert!(abs < 0.001);
+    }
+
+    #[test]
+    fn test_zomma() {
+        let zomma = zomma(UNDERLYING,
+                          STRIKE,
+                          TIME_TO_EXPIRY,
+                          INTEREST_RATE,
+                          DIV_YIELD,
+                          VOL);
+        let abs = (zomma - E_ZOMMA).abs();
+        assert!(abs < 0.001);
+    }
 }